@@ -0,0 +1,79 @@
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// User-configurable settings, persisted as `naeyla.toml` in the platform config dir.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    pub toggle_window_shortcut: String,
+    #[serde(default = "Config::default_capture_shortcut")]
+    pub capture_shortcut: String,
+    #[serde(default)]
+    pub autostart: bool,
+    #[serde(default = "Config::default_true")]
+    pub vibrancy: bool,
+    #[serde(default = "Config::default_true")]
+    pub auto_hide_on_blur: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            toggle_window_shortcut: "CmdOrCtrl+Shift+N".to_string(),
+            capture_shortcut: "CmdOrCtrl+Shift+C".to_string(),
+            autostart: false,
+            vibrancy: true,
+            auto_hide_on_blur: true,
+        }
+    }
+}
+
+impl Config {
+    fn default_capture_shortcut() -> String {
+        Self::default().capture_shortcut
+    }
+
+    fn default_true() -> bool {
+        true
+    }
+
+    fn path() -> Option<PathBuf> {
+        ProjectDirs::from("dev", "naeyla", "Naeyla")
+            .map(|dirs| dirs.config_dir().join("naeyla.toml"))
+    }
+
+    /// Loads the config from disk, writing the defaults on first launch.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => match toml::from_str(&contents) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!(
+                        "naeyla: failed to parse {}: {err}; using defaults for this session without touching the file",
+                        path.display()
+                    );
+                    Self::default()
+                }
+            },
+            Err(_) => {
+                let config = Self::default();
+                let _ = config.save();
+                config
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path().ok_or("could not determine config directory")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let contents = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&path, contents).map_err(|e| e.to_string())
+    }
+}
@@ -1,37 +1,168 @@
-use tauri::Manager;
-use tauri_plugin_global_shortcut::GlobalShortcutExt;
+mod config;
+mod notes;
+mod spotlight;
+
+use config::Config;
+use notes::capture_note;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, WindowEvent};
+use tauri_plugin_autostart::{ManagerExt, MacosLauncher};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
 
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
+#[tauri::command]
+fn get_config(state: tauri::State<Mutex<Config>>) -> Config {
+    state.lock().unwrap().clone()
+}
+
+#[tauri::command]
+fn set_shortcut(app: AppHandle, state: tauri::State<Mutex<Config>>, shortcut: String) -> Result<(), String> {
+    let new_shortcut: Shortcut = shortcut.parse().map_err(|e| format!("invalid shortcut: {e}"))?;
+
+    let mut config = state.lock().unwrap();
+    let old_shortcut: Shortcut = config
+        .toggle_window_shortcut
+        .parse()
+        .map_err(|e| format!("invalid shortcut: {e}"))?;
+
+    app.global_shortcut()
+        .unregister(old_shortcut)
+        .map_err(|e| e.to_string())?;
+    register_toggle_shortcut(&app, new_shortcut)?;
+
+    config.toggle_window_shortcut = shortcut;
+    config.save()
+}
+
+#[tauri::command]
+fn set_autostart(app: AppHandle, state: tauri::State<Mutex<Config>>, enabled: bool) -> Result<(), String> {
+    let autolaunch = app.autolaunch();
+    if enabled {
+        autolaunch.enable().map_err(|e| e.to_string())?;
+    } else {
+        autolaunch.disable().map_err(|e| e.to_string())?;
+    }
+
+    let mut config = state.lock().unwrap();
+    config.autostart = enabled;
+    config.save()
+}
+
+/// Registers `shortcut` to show/hide the main window. Any previous registration of the old
+/// accelerator must be removed by the caller first (see `set_shortcut`).
+fn register_toggle_shortcut(app: &AppHandle, shortcut: Shortcut) -> Result<(), String> {
+    let handle = app.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, _event| {
+            if let Some(window) = handle.get_webview_window("main") {
+                if window.is_visible().unwrap_or(false) {
+                    let _ = window.hide();
+                } else {
+                    summon_as_spotlight(&window);
+                    let _ = window.emit("activate_input_field", ());
+                }
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Registers `shortcut` to summon the main window in quick-capture mode.
+fn register_capture_shortcut(app: &AppHandle, shortcut: Shortcut) -> Result<(), String> {
+    let handle = app.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, _event| {
+            if let Some(window) = handle.get_webview_window("main") {
+                summon_as_spotlight(&window);
+                let _ = window.emit("activate_capture_mode", ());
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Centers `window` on the focused monitor, shows it and takes focus — the common "spotlight"
+/// summon behavior shared by both shortcuts. Backdrop vibrancy is applied once at window
+/// creation, not on every summon.
+fn summon_as_spotlight(window: &tauri::WebviewWindow) {
+    spotlight::center_on_focused_monitor(window);
+    let _ = window.show();
+    let _ = window.set_focus();
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    #[cfg(desktop)]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }));
+    }
+
+    builder
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_autostart::init(
+            MacosLauncher::LaunchAgent,
+            None,
+        ))
         .setup(|app| {
             let handle = app.handle().clone();
-            
-            // Register global shortcut handler
-            #[allow(deprecated)]
-            app.global_shortcut().register("CmdOrCtrl+Shift+8")?;
-            
-            // Listen to shortcut events
-            app.global_shortcut().on_shortcut("CmdOrCtrl+Shift+N", move |_app, _shortcut, _event| {
-                if let Some(window) = handle.get_webview_window("main") {
-                    let _ = if window.is_visible().unwrap_or(false) {
-                        window.hide()
-                    } else {
-                        window.show()
-                    };
+
+            let config = Config::load();
+            let shortcut: Shortcut = config
+                .toggle_window_shortcut
+                .parse()
+                .unwrap_or_else(|_| Config::default().toggle_window_shortcut.parse().unwrap());
+
+            register_toggle_shortcut(&handle, shortcut)?;
+
+            let capture_shortcut: Shortcut = config
+                .capture_shortcut
+                .parse()
+                .unwrap_or_else(|_| Config::default().capture_shortcut.parse().unwrap());
+            register_capture_shortcut(&handle, capture_shortcut)?;
+
+            if config.autostart {
+                let _ = handle.autolaunch().enable();
+            }
+
+            if let Some(window) = handle.get_webview_window("main") {
+                let _ = window.set_decorations(false);
+                let _ = window.set_always_on_top(true);
+
+                if config.vibrancy {
+                    spotlight::apply_backdrop_vibrancy(&window);
                 }
-            })?;
-            
+
+                if config.auto_hide_on_blur {
+                    let window_handle = window.clone();
+                    window.on_window_event(move |event| {
+                        if let WindowEvent::Focused(false) = event {
+                            let _ = window_handle.hide();
+                        }
+                    });
+                }
+            }
+
+            app.manage(Mutex::new(config));
+
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            get_config,
+            set_shortcut,
+            set_autostart,
+            capture_note
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,28 @@
+use chrono::Local;
+use directories::ProjectDirs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+fn notes_path() -> Result<PathBuf, String> {
+    let dirs =
+        ProjectDirs::from("dev", "naeyla", "Naeyla").ok_or("could not determine config directory")?;
+    let dir = dirs.config_dir();
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("notes.md"))
+}
+
+/// Appends a timestamped line to the persistent quick-capture notes file.
+#[tauri::command]
+pub fn capture_note(_app: AppHandle, text: String) -> Result<(), String> {
+    let path = notes_path()?;
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .map_err(|e| e.to_string())?;
+
+    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+    writeln!(file, "- [{timestamp}] {text}").map_err(|e| e.to_string())
+}
@@ -0,0 +1,51 @@
+use tauri::{PhysicalPosition, WebviewWindow};
+
+#[cfg(target_os = "macos")]
+use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+#[cfg(target_os = "windows")]
+use window_vibrancy::apply_acrylic;
+
+/// Repositions `window` to the center of the monitor under the mouse cursor, falling back to the
+/// monitor the window currently occupies if the cursor position can't be resolved.
+pub fn center_on_focused_monitor(window: &WebviewWindow) {
+    let monitor = cursor_monitor(window).or_else(|| window.current_monitor().ok().flatten());
+    let Some(monitor) = monitor else {
+        return;
+    };
+    let Ok(size) = window.outer_size() else {
+        return;
+    };
+
+    let monitor_pos = monitor.position();
+    let monitor_size = monitor.size();
+
+    let x = monitor_pos.x + (monitor_size.width as i32 - size.width as i32) / 2;
+    let y = monitor_pos.y + (monitor_size.height as i32 - size.height as i32) / 2;
+
+    let _ = window.set_position(PhysicalPosition::new(x, y));
+}
+
+/// Finds the monitor the mouse cursor is currently over, if any.
+fn cursor_monitor(window: &WebviewWindow) -> Option<tauri::Monitor> {
+    let cursor = window.cursor_position().ok()?;
+    let monitors = window.available_monitors().ok()?;
+
+    monitors.into_iter().find(|monitor| {
+        let pos = monitor.position();
+        let size = monitor.size();
+        let x = cursor.x as i32;
+        let y = cursor.y as i32;
+
+        x >= pos.x && x < pos.x + size.width as i32 && y >= pos.y && y < pos.y + size.height as i32
+    })
+}
+
+/// Applies the platform's translucent backdrop material to `window`.
+#[allow(unused_variables)]
+pub fn apply_backdrop_vibrancy(window: &WebviewWindow) {
+    #[cfg(target_os = "macos")]
+    let _ = apply_vibrancy(window, NSVisualEffectMaterial::HudWindow, None, None);
+
+    #[cfg(target_os = "windows")]
+    let _ = apply_acrylic(window, Some((18, 18, 18, 125)));
+}